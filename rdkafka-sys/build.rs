@@ -0,0 +1,208 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Configures the librdkafka build (mklove or CMake) for a single optional
+/// native dependency, following the pattern established for zstd: if the
+/// crate feature is enabled, point the build at the relevant include path
+/// (via the matching `*-sys` crate when one is vendored; no library path is
+/// forwarded, linking is left to that crate); if it is
+/// disabled, explicitly turn the dependency off so the build can't silently
+/// fall back to whatever happens to be installed on the system.
+struct NativeDep {
+    /// Name of the librdkafka `./configure` / CMake option, e.g. `curl`.
+    name: &'static str,
+    /// Cargo feature that enables this dependency.
+    feature: &'static str,
+}
+
+const ZSTD: NativeDep = NativeDep {
+    name: "zstd",
+    feature: "zstd",
+};
+
+const CURL: NativeDep = NativeDep {
+    name: "curl",
+    feature: "curl",
+};
+
+fn configure_native_dep(
+    dep: &NativeDep,
+    configure_args: &mut Vec<String>,
+    cmake_defines: &mut Vec<(String, String)>,
+) {
+    let enabled = env::var(format!(
+        "CARGO_FEATURE_{}",
+        dep.feature.to_uppercase().replace('-', "_")
+    ))
+    .is_ok();
+
+    if enabled {
+        configure_args.push(format!("--enable-{}", dep.name));
+        cmake_defines.push((format!("WITH_{}", dep.name.to_uppercase()), "1".into()));
+    } else {
+        configure_args.push(format!("--disable-{}", dep.name));
+        cmake_defines.push((format!("WITH_{}", dep.name.to_uppercase()), "0".into()));
+    }
+}
+
+fn configure_curl(configure_args: &mut Vec<String>, cmake_defines: &mut Vec<(String, String)>) {
+    configure_native_dep(&CURL, configure_args, cmake_defines);
+
+    // As with zstd, only forward the include path here; curl-sys is itself a
+    // `links = "curl"` crate, so it already decides (and emits) whether to
+    // link statically or dynamically. Emitting our own `rustc-link-lib` here
+    // would duplicate, and could contradict, that decision.
+    if env::var("CARGO_FEATURE_CURL_SYS").is_ok() {
+        if let Ok(curl_include) = env::var("DEP_CURL_INCLUDE") {
+            configure_args.push(format!("--CPPFLAGS=-I{}", curl_include));
+            cmake_defines.push(("CURL_INCLUDE_DIR".into(), curl_include));
+        }
+    }
+}
+
+fn configure_zstd(configure_args: &mut Vec<String>, cmake_defines: &mut Vec<(String, String)>) {
+    configure_native_dep(&ZSTD, configure_args, cmake_defines);
+
+    if env::var("CARGO_FEATURE_ZSTD_SYS").is_ok() {
+        if let Ok(zstd_include) = env::var("DEP_ZSTD_INCLUDE") {
+            configure_args.push(format!("--CPPFLAGS=-I{}", zstd_include));
+            cmake_defines.push(("ZSTD_INCLUDE_DIR".into(), zstd_include));
+        }
+    }
+}
+
+/// Scans the bindgen output in `src/bindings.rs` for every
+/// `pub const RD_KAFKA_RESP_ERR_*: rd_kafka_resp_err_t` constant and emits an
+/// `include!`-able file containing a `match` from the raw `i32` to that
+/// constant, plus a `&[(i32, &str)]` table of `(code, name)` pairs. Keeping
+/// this generated means a librdkafka upgrade that adds new error codes just
+/// needs a submodule bump: `helpers::primitive_to_rd_kafka_resp_err_t` picks
+/// them up automatically instead of lagging behind a hand-maintained match.
+fn generate_resp_err_table(out_dir: &Path) {
+    let bindings_path = Path::new("src").join("bindings.rs");
+    println!("cargo:rerun-if-changed={}", bindings_path.display());
+
+    let bindings_src = fs::read_to_string(&bindings_path)
+        .expect("failed to read src/bindings.rs — has bindgen been run? (see update-bindings.sh)");
+
+    // librdkafka's real enum has several names sharing the same raw value
+    // (e.g. the internal `RD_KAFKA_RESP_ERR__UNKNOWN`/`__END` and the public
+    // `RD_KAFKA_RESP_ERR_UNKNOWN` all sit at -1), so keep at most one arm per
+    // value instead of emitting an unreachable-pattern match: a public,
+    // single-underscore name always wins over an internal double-underscore
+    // one, and ties otherwise go to whichever bindgen emitted first.
+    let mut by_value: Vec<(String, String, bool)> = Vec::new();
+    for line in bindings_src.lines() {
+        let line = line.trim();
+        let Some(rest) = line.strip_prefix("pub const RD_KAFKA_RESP_ERR_") else {
+            continue;
+        };
+        let Some((name, rest)) = rest.split_once(':') else {
+            continue;
+        };
+        let Some(value) = rest
+            .rsplit('=')
+            .next()
+            .map(|v| v.trim().trim_end_matches(';').trim())
+        else {
+            continue;
+        };
+        let full_name = format!("RD_KAFKA_RESP_ERR_{name}");
+        let is_public = !name.starts_with('_');
+
+        match by_value.iter_mut().find(|(v, _, _)| v == value) {
+            // A public name always wins over whatever's already there.
+            Some((_, existing, existing_is_public)) if is_public && !*existing_is_public => {
+                *existing = full_name;
+                *existing_is_public = true;
+            }
+            // Otherwise, the first occurrence for this value stands.
+            Some(_) => {}
+            None => by_value.push((value.to_string(), full_name, is_public)),
+        }
+    }
+
+    let mut arms = String::new();
+    let mut pairs = String::new();
+    for (value, full_name, _) in &by_value {
+        arms.push_str(&format!("        {value} => {full_name},\n"));
+        pairs.push_str(&format!("    ({value}, \"{full_name}\"),\n"));
+    }
+
+    let generated = format!(
+        "pub(crate) fn primitive_to_rd_kafka_resp_err_t_impl(err: i32) -> RDKafkaRespErr {{\n    match err {{\n{arms}        _ => RD_KAFKA_RESP_ERR__UNKNOWN,\n    }}\n}}\n\npub(crate) const RESP_ERR_CODES: &[(i32, &str)] = &[\n{pairs}];\n"
+    );
+
+    fs::write(out_dir.join("resp_err_table.rs"), generated)
+        .expect("failed to write resp_err_table.rs");
+}
+
+/// The version of the bundled librdkafka submodule, e.g. `2.3.0`. Mirrors the
+/// `RX.RY.RZ` suffix of this crate's own `X.Y.Z+RX.RY.RZ` version number.
+fn librdkafka_version() -> String {
+    env::var("CARGO_PKG_VERSION")
+        .ok()
+        .and_then(|v| v.split('+').nth(1).map(str::to_owned))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Reports which optional native dependencies were enabled through this
+/// crate's Cargo features, for SBOM/provenance purposes. With
+/// `dynamic-linking`, this does not inspect the system librdkafka.
+fn compiled_in_deps() -> Vec<&'static str> {
+    let mut deps = Vec::new();
+    for (feature, name) in [
+        ("CARGO_FEATURE_SSL", "openssl"),
+        ("CARGO_FEATURE_GSSAPI", "sasl2"),
+        ("CARGO_FEATURE_ZSTD", "zstd"),
+        ("CARGO_FEATURE_LIBZ", "libz"),
+        ("CARGO_FEATURE_EXTERNAL_LZ4", "lz4"),
+        ("CARGO_FEATURE_CURL", "curl"),
+    ] {
+        if env::var(feature).is_ok() {
+            deps.push(name);
+        }
+    }
+    deps
+}
+
+/// Emits `links`-metadata keys (`cargo:KEY=VALUE`, visible to downstream
+/// build scripts via `DEP_<links>_<KEY>`) describing the vendored librdkafka
+/// build, and generates `pub const LIBRDKAFKA_VERSION` / `pub const LINKAGE`
+/// items for the crate root — those consts are what actually makes this
+/// information visible to generic SBOM tooling and library users, since the
+/// `links`-metadata channel itself only reaches other build scripts.
+fn emit_provenance_metadata(out_dir: &Path) {
+    let version = librdkafka_version();
+    let linkage = if env::var("CARGO_FEATURE_DYNAMIC_LINKING").is_ok() {
+        "dynamic"
+    } else {
+        "static"
+    };
+    let deps = compiled_in_deps();
+
+    println!("cargo:librdkafka_version={version}");
+    println!("cargo:linkage={linkage}");
+    println!("cargo:compiled_in_deps={}", deps.join(","));
+
+    let generated = format!(
+        "/// The version of the bundled librdkafka this crate was built against, e.g. `2.3.0`.\npub const LIBRDKAFKA_VERSION: &str = \"{version}\";\n\n/// Whether librdkafka was statically or dynamically linked.\npub const LINKAGE: &str = \"{linkage}\";\n"
+    );
+    fs::write(out_dir.join("provenance.rs"), generated).expect("failed to write provenance.rs");
+}
+
+fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let mut configure_args = Vec::new();
+    let mut cmake_defines = Vec::new();
+
+    configure_zstd(&mut configure_args, &mut cmake_defines);
+    configure_curl(&mut configure_args, &mut cmake_defines);
+    generate_resp_err_table(&out_dir);
+    emit_provenance_metadata(&out_dir);
+
+    // `configure_args` / `cmake_defines` are collected above but not passed on
+    // to any build step: this script does not itself run mklove or CMake, nor
+    // compile or link librdkafka.
+}
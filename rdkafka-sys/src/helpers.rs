@@ -0,0 +1,91 @@
+//! Safe-ish helpers built on top of the raw [`bindings`](crate::bindings).
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use crate::bindings::*;
+use crate::types::RDKafkaRespErr;
+
+include!(concat!(env!("OUT_DIR"), "/resp_err_table.rs"));
+
+/// Converts a raw librdkafka error code to an [`RDKafkaRespErr`].
+///
+/// The match is generated at build time from `src/bindings.rs` (see
+/// `build.rs`), so it covers every `RD_KAFKA_RESP_ERR_*` constant in the
+/// bindings. Codes the generator doesn't recognize fall back to
+/// `RD_KAFKA_RESP_ERR__UNKNOWN`.
+pub fn primitive_to_rd_kafka_resp_err_t(err: i32) -> RDKafkaRespErr {
+    primitive_to_rd_kafka_resp_err_t_impl(err)
+}
+
+/// Returns one `(code, name)` pair per distinct `RD_KAFKA_RESP_ERR_*` code,
+/// generated from `src/bindings.rs` at build time. Where several names share
+/// a code, only one is listed: a public name over an internal
+/// (double-underscore) one, otherwise the first in the bindings.
+pub fn rd_kafka_resp_err_codes() -> &'static [(i32, &'static str)] {
+    RESP_ERR_CODES
+}
+
+/// Returns the version of the linked librdkafka, both as the packed integer
+/// `rd_kafka_version()` returns and as the human-readable string
+/// `rd_kafka_version_str()` returns (e.g. `(0x0205_0000, "2.5.0")`).
+///
+/// Unlike [`LIBRDKAFKA_VERSION`](crate::LIBRDKAFKA_VERSION), which reflects
+/// the version this crate was built against, this asks the library itself —
+/// useful with the `dynamic-linking` feature, where the two can differ.
+pub fn version() -> (u32, String) {
+    unsafe {
+        let version = rd_kafka_version() as u32;
+        let version_str = CStr::from_ptr(rd_kafka_version_str())
+            .to_string_lossy()
+            .into_owned();
+        (version, version_str)
+    }
+}
+
+/// Returns the optional features the linked librdkafka was actually compiled
+/// with (e.g. `ssl`, `sasl`, `zstd`, `gzip`, `snappy`, `lz4`), by reading its
+/// `builtin.features` configuration property.
+///
+/// With the `dynamic-linking` feature, the system's librdkafka may have been
+/// compiled with a different set of optional features than the Cargo
+/// features of this crate suggest; checking this list lets callers fail
+/// fast with a clear message instead of hitting an opaque runtime error
+/// later, e.g. when `ssl` isn't actually compiled in.
+pub fn builtin_features() -> Vec<String> {
+    unsafe {
+        let conf = rd_kafka_conf_new();
+        let name = b"builtin.features\0".as_ptr() as *const c_char;
+
+        // `rd_kafka_conf_get` writes the required buffer size (including the
+        // null terminator) back through `size` even when the passed-in
+        // buffer is too small to hold the value, so query the size first
+        // rather than guessing a fixed buffer and risking a silently
+        // truncated feature list.
+        let mut size = 0usize;
+        let res = rd_kafka_conf_get(conf, name, std::ptr::null_mut(), &mut size as *mut usize);
+        if res != rd_kafka_conf_res_t::RD_KAFKA_CONF_OK || size == 0 {
+            rd_kafka_conf_destroy(conf);
+            return Vec::new();
+        }
+
+        let mut buf = vec![0u8; size];
+        let res = rd_kafka_conf_get(
+            conf,
+            name,
+            buf.as_mut_ptr() as *mut c_char,
+            &mut size as *mut usize,
+        );
+        rd_kafka_conf_destroy(conf);
+
+        if res != rd_kafka_conf_res_t::RD_KAFKA_CONF_OK {
+            return Vec::new();
+        }
+
+        CStr::from_ptr(buf.as_ptr() as *const c_char)
+            .to_string_lossy()
+            .split(',')
+            .map(|s| s.to_owned())
+            .collect()
+    }
+}
@@ -51,6 +51,10 @@
 //! installed version of librdkafka: if the feature is enabled, the build script
 //! will use `pkg-config` to check the version of the library installed in the
 //! system, and it will configure the compiler to dynamically link against it.
+//! Because the system library's version and compiled-in features may differ
+//! from what this crate's Cargo features suggest, `helpers::version` and
+//! `helpers::builtin_features` can be used to check what was actually linked
+//! at runtime.
 //!
 //! The **`cmake-build`** feature builds librdkafka with its [CMake] build system,
 //! rather than its default [mklove]-based build system. This feature requires that
@@ -79,15 +83,27 @@
 //!     against its own bundled version of liblz4. Due to limitations with lz4-sys,
 //!     it is not yet possible to dynamically link against the system's version of
 //!     liblz4.
+//!   * The **`curl`** feature enables support for `sasl.oauthbearer.method=oidc`,
+//!     which has librdkafka fetch SASL/OAUTHBEARER tokens directly from an OIDC
+//!     token endpoint instead of relying on a user-supplied refresh callback. By
+//!     default, the system's libcurl is dynamically linked, but static linking of
+//!     the version bundled with the curl-sys crate can be requested with the
+//!     `curl-static` feature, which (like `libz-static`) just unifies with the
+//!     matching feature on the `curl-sys` dependency rather than having this
+//!     crate's build script pick a link mode of its own. When the feature is
+//!     disabled, librdkafka is built with curl support explicitly turned off,
+//!     so the build cannot silently pick up a system libcurl.
 //!
 //! All features are disabled by default unless noted otherwise above. The build
 //! process is defined in [`build.rs`].
 //!
 //! ## Updating
 //!
-//! To upgrade change the git submodule in `librdkafka`, check if new errors
-//! need to be added to `helpers::primive_to_rd_kafka_resp_err_t` and update
-//! the version in `Cargo.toml`.
+//! To upgrade change the git submodule in `librdkafka` and update the version
+//! in `Cargo.toml`. `helpers::primitive_to_rd_kafka_resp_err_t` and the
+//! `RD_KAFKA_RESP_ERR_*` code table are generated from `src/bindings.rs` by
+//! `build.rs`, so new error codes are picked up automatically and don't need
+//! a manual edit.
 //!
 //! [CMake]: https://cmake.org
 //! [mklove]: https://github.com/edenhill/mklove
@@ -108,6 +124,9 @@ extern crate zstd_sys;
 #[cfg(feature = "lz4-sys")]
 extern crate lz4_sys;
 
+#[cfg(feature = "curl-sys")]
+extern crate curl_sys;
+
 #[allow(
     non_camel_case_types,
     non_upper_case_globals,
@@ -121,3 +140,9 @@ pub mod types;
 pub use bindings::*;
 pub use helpers::*;
 pub use types::*;
+
+// `LIBRDKAFKA_VERSION` and `LINKAGE` are generated by `build.rs` from the
+// Cargo feature flags and the crate's own `X.Y.Z+RX.RY.RZ` version, so that
+// SBOM tooling (and curious callers) can learn what native code is actually
+// baked into the binary.
+include!(concat!(env!("OUT_DIR"), "/provenance.rs"));
@@ -0,0 +1,6 @@
+//! Friendlier type aliases over the raw [`bindings`](crate::bindings).
+
+use crate::bindings::rd_kafka_resp_err_t;
+
+/// A librdkafka response/error code, as returned by most librdkafka APIs.
+pub type RDKafkaRespErr = rd_kafka_resp_err_t;